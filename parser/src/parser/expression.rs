@@ -33,6 +33,70 @@ const INT_TYPES: &[Token] = &[
     Token::Group,
 ];
 
+/// Associativity of a binary operator, used by the precedence-climbing loop in
+/// [`ParserContext::parse_binary_expression`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Assoc {
+    Left,
+    Right,
+}
+
+/// Binary operators `parse_binary_expression` recognizes, together with their
+/// precedence (higher binds tighter) and associativity. Mirrors the precedence that
+/// used to be encoded as a ladder of one `parse_*_expression` method per level.
+const BINARY_OPS: &[(Token, u8, Assoc)] = &[
+    (Token::Or, 1, Assoc::Left),
+    (Token::And, 2, Assoc::Left),
+    (Token::BitOr, 3, Assoc::Left),
+    (Token::BitXor, 4, Assoc::Left),
+    (Token::BitAnd, 5, Assoc::Left),
+    (Token::Eq, 6, Assoc::Left),
+    (Token::NotEq, 6, Assoc::Left),
+    (Token::Lt, 7, Assoc::Left),
+    (Token::LtEq, 7, Assoc::Left),
+    (Token::Gt, 7, Assoc::Left),
+    (Token::GtEq, 7, Assoc::Left),
+    (Token::Shl, 8, Assoc::Left),
+    (Token::Shr, 8, Assoc::Left),
+    (Token::ShrSigned, 8, Assoc::Left),
+    (Token::Add, 9, Assoc::Left),
+    (Token::Minus, 9, Assoc::Left),
+    (Token::Mul, 10, Assoc::Left),
+    (Token::Div, 10, Assoc::Left),
+    (Token::Mod, 10, Assoc::Left),
+    (Token::Exp, 11, Assoc::Right),
+];
+
+fn binary_op_entry(token: &Token) -> Option<&'static (Token, u8, Assoc)> {
+    BINARY_OPS.iter().find(|(op, _, _)| op == token)
+}
+
+fn token_to_binary_op(token: &Token) -> BinaryOperation {
+    match token {
+        Token::Or => BinaryOperation::Or,
+        Token::And => BinaryOperation::And,
+        Token::BitOr => BinaryOperation::BitOr,
+        Token::BitXor => BinaryOperation::BitXor,
+        Token::BitAnd => BinaryOperation::BitAnd,
+        Token::Eq => BinaryOperation::Eq,
+        Token::NotEq => BinaryOperation::Ne,
+        Token::Lt => BinaryOperation::Lt,
+        Token::LtEq => BinaryOperation::Le,
+        Token::Gt => BinaryOperation::Gt,
+        Token::GtEq => BinaryOperation::Ge,
+        Token::Shl => BinaryOperation::Shl,
+        Token::Shr => BinaryOperation::Shr,
+        Token::ShrSigned => BinaryOperation::ShrSigned,
+        Token::Add => BinaryOperation::Add,
+        Token::Minus => BinaryOperation::Sub,
+        Token::Mul => BinaryOperation::Mul,
+        Token::Div => BinaryOperation::Div,
+        Token::Mod => BinaryOperation::Mod,
+        Token::Exp => BinaryOperation::Pow,
+        _ => unimplemented!(),
+    }
+}
+
 impl ParserContext {
     ///
     /// Returns an [`Expression`] AST node if the next token is an expression.
@@ -51,27 +115,43 @@ impl ParserContext {
         // Restore prior parser state.
         self.fuzzy_struct_state = prior_fuzzy_state;
 
-        result
+        result.map(|expr| self.normalize_expression(expr))
+    }
+
+    ///
+    /// Returns an [`Expression`] AST node if the next tokens represent a range
+    /// expression (`start..end` or the inclusive `start..=end`). May or may not
+    /// include circuit init expressions.
+    ///
+    /// Otherwise, tries to parse the next token using [`parse_ternary_expression`].
+    ///
+    pub fn parse_expression_fuzzy(&mut self) -> SyntaxResult<Expression> {
+        let expr = self.parse_ternary_expression()?;
+        self.parse_range_expression(expr)
     }
 
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent
     /// a ternary expression. May or may not include circuit init expressions.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_or_expression`].
+    /// Otherwise, tries to parse the next token using [`parse_binary_expression`].
     ///
-    pub fn parse_expression_fuzzy(&mut self) -> SyntaxResult<Expression> {
-        // Try to parse the next expression. Try BinaryOperation::Or.
-        let mut expr = self.parse_or_expression()?;
+    fn parse_ternary_expression(&mut self) -> SyntaxResult<Expression> {
+        // Try to parse the next expression.
+        let mut expr = self.parse_binary_expression()?;
 
         // Parse the rest of the ternary expression.
         if self.eat(Token::Question).is_some() {
+            // `if_true` is parsed through `parse_expression`, which already normalizes
+            // its result; `condition` and `if_false` come from lower-level entry
+            // points that don't, so normalize them explicitly before building the node.
+            let condition = self.normalize_expression(expr);
             let if_true = self.parse_expression()?;
             self.expect(Token::Colon)?;
-            let if_false = self.parse_expression_fuzzy()?;
+            let if_false = self.normalize_expression(self.parse_expression_fuzzy()?);
             expr = Expression::Ternary(TernaryExpression {
-                span: expr.span() + if_false.span(),
-                condition: Box::new(expr),
+                span: condition.span() + if_false.span(),
+                condition: Box::new(condition),
                 if_true: Box::new(if_true),
                 if_false: Box::new(if_false),
             });
@@ -80,253 +160,230 @@ impl ParserContext {
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent
-    /// a binary or expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_and_expression`].
-    ///
-    pub fn parse_or_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_and_expression()?;
-        while self.eat(Token::Or).is_some() {
-            let right = self.parse_and_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: BinaryOperation::Or,
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
-        }
-        Ok(expr)
+    /// Given the already-parsed `start` of a range, consumes a trailing `..` or `..=`
+    /// and its upper bound, producing a [`RangeExpression`]. At the top level a range
+    /// binds looser than the ternary operator, but a ternary's `if_false` arm is itself
+    /// parsed with `parse_expression_fuzzy`, so `cond ? a : b .. c` parses as
+    /// `cond ? a : (b .. c)`. The upper bound of an exclusive `..` may be omitted
+    /// (`a..`) when the next token can't start an expression; an inclusive `..=`
+    /// always requires one. Returns `start` unchanged if no range operator follows.
+    ///
+    fn parse_range_expression(&mut self, start: Expression) -> SyntaxResult<Expression> {
+        let inclusive = if self.eat(Token::DotDotEq).is_some() {
+            true
+        } else if self.eat(Token::DotDot).is_some() {
+            false
+        } else {
+            return Ok(start);
+        };
+
+        // Neither bound passes through `parse_expression`, so normalize both
+        // explicitly before they become children of the `RangeExpression`.
+        let start = self.normalize_expression(start);
+        let end = if inclusive || !self.is_range_terminator() {
+            let end = self.parse_ternary_expression()?;
+            Some(Box::new(self.normalize_expression(end)))
+        } else {
+            None
+        };
+        let span = match &end {
+            Some(end) => start.span() + end.span(),
+            None => start.span() + &self.prev_token.span,
+        };
+        Ok(Expression::Range(RangeExpression {
+            span,
+            start: Some(Box::new(start)),
+            end,
+            inclusive,
+        }))
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary and expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_bit_or_expression`].
-    ///
-    pub fn parse_and_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_bit_or_expression()?;
-        while self.eat(Token::And).is_some() {
-            let right = self.parse_bit_or_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: BinaryOperation::And,
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+    /// True if the upcoming token cannot start an expression and therefore ends an
+    /// omitted range bound (e.g. the `]` in `a..]` or the end of input).
+    ///
+    fn is_range_terminator(&mut self) -> bool {
+        match self.peek() {
+            Ok(next) => matches!(
+                next.token,
+                Token::RightSquare | Token::RightParen | Token::RightCurly | Token::Comma | Token::Semicolon
+            ),
+            Err(_) => true,
         }
-        Ok(expr)
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary bitwise or expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_bit_xor_expression`].
-    ///
-    pub fn parse_bit_or_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_bit_xor_expression()?;
-        while self.eat(Token::BitOr).is_some() {
-            let right = self.parse_bit_xor_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: BinaryOperation::BitOr,
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+    /// Returns an [`Expression`] AST node by precedence-climbing over [`BINARY_OPS`].
+    ///
+    /// Parses one operand with [`parse_cast_expression`], then repeatedly folds an
+    /// explicit operator/operand stack instead of recursing through a method per
+    /// precedence level, so stack depth no longer grows with the number of operators
+    /// in a flat expression like `a + b + c + ...`.
+    ///
+    pub fn parse_binary_expression(&mut self) -> SyntaxResult<Expression> {
+        let mut operands = vec![self.parse_cast_expression()?];
+        let mut operators: Vec<(Token, u8, Assoc)> = Vec::new();
+
+        loop {
+            // Read directly off the cursor rather than `peek()?`: at EOF the cursor
+            // holds the `Eof` sentinel (not an error), so a bare expression ending the
+            // input just falls through to `None` and breaks instead of propagating `Err`.
+            let (op_token, prec, assoc) = match binary_op_entry(&self.token) {
+                Some(entry) => entry.clone(),
+                None => break,
+            };
+
+            // Fold while the top of the stack binds at least as tightly as `op_token`
+            // (strictly tighter for right-associative operators, so e.g. `Exp` nests right).
+            while let Some((_, top_prec, top_assoc)) = operators.last() {
+                let should_fold = match top_assoc {
+                    Assoc::Left => *top_prec >= prec,
+                    Assoc::Right => *top_prec > prec,
+                };
+                if !should_fold {
+                    break;
+                }
+                Self::fold_operator(&mut operands, &mut operators);
+            }
+
+            self.expect(op_token.clone())?;
+            operators.push((op_token, prec, assoc));
+            operands.push(self.parse_cast_expression()?);
         }
-        Ok(expr)
-    }
 
-    ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary bitwise xor expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_bit_and_expression`].
-    ///
-    pub fn parse_bit_xor_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_bit_and_expression()?;
-        while self.eat(Token::BitXor).is_some() {
-            let right = self.parse_bit_and_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: BinaryOperation::BitXor,
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+        while !operators.is_empty() {
+            Self::fold_operator(&mut operands, &mut operators);
         }
-        Ok(expr)
+
+        Ok(operands.pop().expect("parse_binary_expression always produces an operand"))
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary bitwise and expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_eq_expression`].
-    ///
-    pub fn parse_bit_and_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_eq_expression()?;
-        while self.eat(Token::BitAnd).is_some() {
-            let right = self.parse_eq_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: BinaryOperation::BitAnd,
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
-        }
-        Ok(expr)
+    /// Pops one operator and its two operands off the given stacks, folding them into
+    /// a [`BinaryExpression`] and pushing the result back onto the operand stack.
+    ///
+    fn fold_operator(operands: &mut Vec<Expression>, operators: &mut Vec<(Token, u8, Assoc)>) {
+        let (op_token, _, _) = operators.pop().expect("fold_operator requires a non-empty operator stack");
+        let right = operands.pop().expect("fold_operator requires a right operand");
+        let left = operands.pop().expect("fold_operator requires a left operand");
+        operands.push(Expression::Binary(BinaryExpression {
+            span: left.span() + right.span(),
+            op: token_to_binary_op(&op_token),
+            left: Box::new(left),
+            right: Box::new(right),
+        }));
     }
 
+    /// Above this many buffered diagnostics, recovery gives up synthesizing
+    /// placeholder nodes and lets the next syntax error propagate normally instead —
+    /// a pathologically broken file shouldn't turn into an unbounded run of
+    /// `Expression::Err` nodes.
+    const MAX_RECOVERABLE_ERRORS: usize = 32;
+
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary equals or not equals expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_rel_expression`].
-    ///
-    pub fn parse_eq_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_rel_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Eq, Token::NotEq]) {
-            let right = self.parse_rel_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: match op {
-                    Token::Eq => BinaryOperation::Eq,
-                    Token::NotEq => BinaryOperation::Ne,
-                    _ => unimplemented!(),
-                },
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+    /// Emits `err` through the parser's diagnostic handler, unless the handler has
+    /// already buffered [`Self::MAX_RECOVERABLE_ERRORS`] diagnostics, in which case
+    /// `err` is returned so the caller bails instead of recovering further.
+    ///
+    fn emit_recoverable(&mut self, err: SyntaxError) -> SyntaxResult<()> {
+        if self.handler.err_count() >= Self::MAX_RECOVERABLE_ERRORS {
+            return Err(err);
         }
-        Ok(expr)
+        self.handler.emit_err(err.into());
+        Ok(())
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary relational expression: less than, less than or equals, greater than, greater than or equals.
+    /// Emits `err` through the parser's diagnostic handler, skips tokens up to the
+    /// next synchronizing token, and returns an [`Expression::Err`] placeholder
+    /// covering the skipped region so the caller can keep building an AST instead of
+    /// aborting the whole parse on the first malformed expression. Once the handler
+    /// has buffered [`Self::MAX_RECOVERABLE_ERRORS`] diagnostics, bails with `err`
+    /// instead of recovering further.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_shift_expression`].
-    ///    
-    pub fn parse_rel_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_shift_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Lt, Token::LtEq, Token::Gt, Token::GtEq])
-        {
-            let right = self.parse_shift_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: match op {
-                    Token::Lt => BinaryOperation::Lt,
-                    Token::LtEq => BinaryOperation::Le,
-                    Token::Gt => BinaryOperation::Gt,
-                    Token::GtEq => BinaryOperation::Ge,
-                    _ => unimplemented!(),
-                },
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
-        }
-        Ok(expr)
+    fn recover_expression(&mut self, err: SyntaxError, span: Span) -> SyntaxResult<Expression> {
+        self.emit_recoverable(err)?;
+        let span = self.synchronize(span);
+        Ok(Expression::Err(ErrExpression { span }))
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary shift expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_add_sub_expression`].
-    ///
-    pub fn parse_shift_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_add_sub_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Shl, Token::Shr, Token::ShrSigned]) {
-            let right = self.parse_add_sub_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: match op {
-                    Token::Shl => BinaryOperation::Shl,
-                    Token::Shr => BinaryOperation::Shr,
-                    Token::ShrSigned => BinaryOperation::ShrSigned,
-                    _ => unimplemented!(),
-                },
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+    /// Called by a tuple/array/call element loop after a trailing `,` wasn't found:
+    /// the no-comma case is the normal way such a loop ends, so this first tries to
+    /// eat `close` itself and reports success without emitting anything. Only when
+    /// the next token is neither `,` nor `close` does it emit a diagnostic describing
+    /// what was found, synchronize past the bad tokens, and report whether `close` was
+    /// the token reached (the caller should break its loop) or not (the caller should
+    /// keep trying to parse elements).
+    ///
+    fn recover_list_separator(&mut self, close: Token, expected: &str) -> SyntaxResult<bool> {
+        if self.eat(close.clone()).is_some() {
+            return Ok(true);
         }
-        Ok(expr)
+        let next = self.peek()?;
+        let span = next.span.clone();
+        self.emit_recoverable(SyntaxError::unexpected_str(&next.token, expected, &span))?;
+        self.synchronize(span);
+        Ok(self.eat(close).is_some())
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary addition or subtraction expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_mul_div_pow_expression`].
-    ///
-    pub fn parse_add_sub_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_mul_div_mod_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Add, Token::Minus]) {
-            let right = self.parse_mul_div_mod_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: match op {
-                    Token::Add => BinaryOperation::Add,
-                    Token::Minus => BinaryOperation::Sub,
-                    _ => unimplemented!(),
-                },
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
+    /// Skips tokens until a synchronizing token (`,`, `)`, `]`, `}`, `;`) or the end of
+    /// input is reached, without consuming the token it stops on, extending `span` to
+    /// cover whatever was skipped.
+    ///
+    fn synchronize(&mut self, mut span: Span) -> Span {
+        const RECOVERY_TOKENS: &[Token] = &[
+            Token::Comma,
+            Token::RightParen,
+            Token::RightSquare,
+            Token::RightCurly,
+            Token::Semicolon,
+        ];
+        while let Ok(next) = self.peek() {
+            if RECOVERY_TOKENS.contains(&next.token) {
+                break;
+            }
+            match self.expect_any() {
+                Ok(skipped) => span = span + skipped.span,
+                Err(_) => break,
+            }
         }
-        Ok(expr)
+        span
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary multiplication, division, or modulus expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_exp_expression`].
-    ///
-    pub fn parse_mul_div_mod_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_exp_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Mul, Token::Div, Token::Mod]) {
-            let right = self.parse_exp_expression()?;
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + right.span(),
-                op: match op {
-                    Token::Mul => BinaryOperation::Mul,
-                    Token::Div => BinaryOperation::Div,
-                    Token::Mod => BinaryOperation::Mod,
-                    _ => unimplemented!(),
-                },
-                left: Box::new(expr),
-                right: Box::new(right),
-            })
-        }
-        Ok(expr)
+    /// Single-token lookahead used to disambiguate a circuit-init `Foo { ... }` from a
+    /// block: true when the token after the upcoming `{` is one `parse_circuit_init`
+    /// can actually start a member list with — an identifier (covers `{ x: 1 }`, the
+    /// shorthand `{ x }` / `{ x, y }`, and everything in between) or the immediate
+    /// `}` of an empty `Foo {}`.
+    ///
+    fn peek_is_circuit_init(&self) -> bool {
+        matches!(
+            self.look_ahead(1).map(|t| &t.token),
+            Some(Token::Ident(_)) | Some(Token::BigSelf) | Some(Token::RightCurly)
+        )
     }
 
     ///
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary exponentiation expression.
+    /// True if the current token is `token`, read directly off the cursor. Unlike
+    /// `peek()?`, this can't fail: the cursor always holds a valid token (an `Eof`
+    /// sentinel once the input is exhausted), so callers that only want a yes/no
+    /// answer don't need to thread a `Result` through.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_cast_expression`].
-    ///
-    pub fn parse_exp_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut exprs = vec![self.parse_cast_expression()?];
+    fn check(&self, token: &Token) -> bool {
+        &self.token == token
+    }
 
-        while self.eat(Token::Exp).is_some() {
-            exprs.push(self.parse_cast_expression()?);
-        }
-        let mut expr = exprs.remove(exprs.len() - 1);
-        while !exprs.is_empty() {
-            let sub_expr = exprs.remove(exprs.len() - 1);
-            expr = Expression::Binary(BinaryExpression {
-                span: expr.span() + sub_expr.span(),
-                op: BinaryOperation::Pow,
-                left: Box::new(sub_expr),
-                right: Box::new(expr),
-            })
-        }
-        Ok(expr)
+    ///
+    /// True when `right` begins exactly where `left` ends in the source text, with no
+    /// whitespace or comments between them. Used to require a type suffix (`5u8`,
+    /// `5field`, `5group`) to be lexically glued to the literal it modifies rather
+    /// than merely the next token in the stream.
+    ///
+    fn is_adjacent(left: &Span, right: &Span) -> bool {
+        left.line_stop == right.line_start && left.col_stop == right.col_start
     }
 
     ///
@@ -367,23 +424,6 @@ impl ParserContext {
                 Token::BitNot => UnaryOperation::BitNot,
                 _ => unimplemented!(),
             };
-            // hack for const signed integer overflow issues
-            if matches!(operation, UnaryOperation::Negate) {
-                if let Expression::Value(ValueExpression::Integer(type_, value, span)) = inner {
-                    inner = Expression::Value(ValueExpression::Integer(
-                        type_,
-                        format_tendril!("-{}", value),
-                        &op.span + &span,
-                    ));
-                    continue;
-                } else if let Expression::Value(ValueExpression::Implicit(value, span)) = inner {
-                    inner = Expression::Value(ValueExpression::Implicit(
-                        format_tendril!("-{}", value),
-                        &op.span + &span,
-                    ));
-                    continue;
-                }
-            }
             inner = Expression::Unary(UnaryExpression {
                 span: &op.span + inner.span(),
                 op: operation,
@@ -393,6 +433,148 @@ impl ParserContext {
         Ok(inner)
     }
 
+    ///
+    /// Desugars a [`BinaryExpression`] or [`UnaryExpression`] into its canonical form
+    /// and constant-folds operations whose operands are both literal integers of the
+    /// same declared type. Folds that would overflow the declared width are reported
+    /// through `handler` rather than deferred to a later compiler stage. Generalizes
+    /// the unary-negate hack that used to live directly inside `parse_unary_expression`.
+    ///
+    /// This only recurses through `Unary`/`Binary`/`Cast` — it isn't a full-tree walk. Every
+    /// other expression kind that nests a sub-expression (`Ternary`, `Range`, calls,
+    /// composite literals, ...) is expected to call this itself on each child it
+    /// parses outside of `parse_expression` (which already normalizes its result).
+    /// `parse_ternary_expression` and `parse_range_expression` do this for their
+    /// condition/bound children; keep that pattern when adding new composite forms.
+    ///
+    fn normalize_expression(&mut self, expr: Expression) -> Expression {
+        match expr {
+            Expression::Unary(unary) => self.normalize_unary(unary),
+            Expression::Binary(binary) => self.normalize_binary(binary),
+            // A cast's operand comes straight from `parse_unary_expression`, so a
+            // negated literal under `as` (`-128i8 as i16`) is folded here.
+            Expression::Cast(CastExpression {
+                span,
+                inner,
+                target_type,
+            }) => Expression::Cast(CastExpression {
+                span,
+                inner: Box::new(self.normalize_expression(*inner)),
+                target_type,
+            }),
+            expr => expr,
+        }
+    }
+
+    fn normalize_unary(&mut self, unary: UnaryExpression) -> Expression {
+        let inner = self.normalize_expression(*unary.inner);
+        if let UnaryOperation::Negate = unary.op {
+            match inner {
+                Expression::Value(ValueExpression::Integer(type_, value, _)) => {
+                    return Expression::Value(ValueExpression::Integer(type_, format_tendril!("-{}", value), unary.span));
+                }
+                Expression::Value(ValueExpression::Implicit(value, _)) => {
+                    return Expression::Value(ValueExpression::Implicit(format_tendril!("-{}", value), unary.span));
+                }
+                inner => {
+                    return Expression::Unary(UnaryExpression {
+                        span: unary.span,
+                        op: unary.op,
+                        inner: Box::new(inner),
+                    });
+                }
+            }
+        }
+        Expression::Unary(UnaryExpression {
+            span: unary.span,
+            op: unary.op,
+            inner: Box::new(inner),
+        })
+    }
+
+    fn normalize_binary(&mut self, binary: BinaryExpression) -> Expression {
+        let left = self.normalize_expression(*binary.left);
+        let right = self.normalize_expression(*binary.right);
+
+        if let (
+            Expression::Value(ValueExpression::Integer(left_type, left_value, left_span)),
+            Expression::Value(ValueExpression::Integer(right_type, right_value, right_span)),
+        ) = (&left, &right)
+        {
+            // I128/U128 don't fit the i128 accumulator `fold_integer_op` works in, so
+            // leave them unfolded entirely rather than routing them through the
+            // overflow branch below (they'd never pass `checked_for_width`).
+            if left_type == right_type && Self::integer_width_bounds(left_type).is_some() {
+                if let Some(raw) = Self::fold_integer_op(binary.op, &left_value.to_string(), &right_value.to_string()) {
+                    let span = left_span.clone() + right_span.clone();
+                    if let Some(folded) = Self::checked_for_width(left_type, raw) {
+                        return Expression::Value(ValueExpression::Integer(left_type.clone(), format_tendril!("{}", folded), span));
+                    }
+                    // Emit the diagnostic but keep the original operator and operands —
+                    // collapsing to just `left` would silently drop the right operand
+                    // and hand downstream passes a wrong AST.
+                    self.handler.emit_err(SyntaxError::overflowing_literal(&span).into());
+                }
+            }
+        }
+
+        Expression::Binary(BinaryExpression {
+            span: binary.span,
+            op: binary.op,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+
+    ///
+    /// Constant-folds `op` over two literal integers, returning `None` for operators
+    /// this pass doesn't fold (comparisons, division, shifts, exponentiation, ...) or
+    /// when the underlying arithmetic itself doesn't fit an `i128` accumulator.
+    ///
+    fn fold_integer_op(op: BinaryOperation, left: &str, right: &str) -> Option<i128> {
+        let left: i128 = left.parse().ok()?;
+        let right: i128 = right.parse().ok()?;
+        match op {
+            BinaryOperation::Add => left.checked_add(right),
+            BinaryOperation::Sub => left.checked_sub(right),
+            BinaryOperation::Mul => left.checked_mul(right),
+            BinaryOperation::BitAnd => Some(left & right),
+            BinaryOperation::BitOr => Some(left | right),
+            BinaryOperation::BitXor => Some(left ^ right),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the inclusive `(min, max)` range representable by `ty`, or `None` for
+    /// `I128`/`U128` — those widths don't fit an `i128` accumulator, so folding is
+    /// skipped for them entirely rather than checked against a range.
+    ///
+    fn integer_width_bounds(ty: &IntegerType) -> Option<(i128, i128)> {
+        Some(match ty {
+            IntegerType::I8 => (i8::MIN as i128, i8::MAX as i128),
+            IntegerType::I16 => (i16::MIN as i128, i16::MAX as i128),
+            IntegerType::I32 => (i32::MIN as i128, i32::MAX as i128),
+            IntegerType::I64 => (i64::MIN as i128, i64::MAX as i128),
+            IntegerType::U8 => (u8::MIN as i128, u8::MAX as i128),
+            IntegerType::U16 => (u16::MIN as i128, u16::MAX as i128),
+            IntegerType::U32 => (u32::MIN as i128, u32::MAX as i128),
+            IntegerType::U64 => (u64::MIN as i128, u64::MAX as i128),
+            IntegerType::I128 | IntegerType::U128 => return None,
+        })
+    }
+
+    ///
+    /// Returns `value` if it fits within the inclusive range representable by `ty`, or
+    /// `None` on overflow. Always `None` for `I128`/`U128`; callers that only want to
+    /// know whether folding applies at all should check [`Self::integer_width_bounds`]
+    /// instead of relying on this returning `None` for two different reasons.
+    ///
+    fn checked_for_width(ty: &IntegerType, value: i128) -> Option<i128> {
+        let (lo, hi) = Self::integer_width_bounds(ty)?;
+        (value >= lo && value <= hi).then_some(value)
+    }
+
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent an
     /// array access, circuit member access, function call, or static function call expression.
@@ -404,46 +586,33 @@ impl ParserContext {
         while let Some(token) = self.eat_any(&[Token::LeftSquare, Token::Dot, Token::LeftParen, Token::DoubleColon]) {
             match token.token {
                 Token::LeftSquare => {
-                    if self.eat(Token::DotDot).is_some() {
-                        let right = if self.peek()?.token != Token::RightSquare {
-                            Some(Box::new(self.parse_expression()?))
-                        } else {
-                            None
-                        };
-
-                        let end = self.expect(Token::RightSquare)?;
-                        expr = Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
-                            span: expr.span() + &end,
+                    // `parse_expression` folds any `..`/`..=` — leading, trailing, or
+                    // bare — into a `RangeExpression`, so a single parse covers plain
+                    // indexing and every slice form (`a[..]`, `a[1..]`, `a[..3]`, `a[1..3]`).
+                    let index = self.parse_expression()?;
+                    self.expect(Token::RightSquare)?;
+                    expr = match index {
+                        Expression::Range(range) => Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
+                            span: expr.span() + &self.prev_token.span,
                             array: Box::new(expr),
-                            left: None,
-                            right,
-                        });
-                        continue;
-                    }
-
-                    let left = self.parse_expression()?;
-                    if self.eat(Token::DotDot).is_some() {
-                        let right = if self.peek()?.token != Token::RightSquare {
-                            Some(Box::new(self.parse_expression()?))
-                        } else {
-                            None
-                        };
-
-                        let end = self.expect(Token::RightSquare)?;
-                        expr = Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
-                            span: expr.span() + &end,
+                            left: range.start,
+                            // The upper bound here is exclusive, so `a[i..=j]` is lowered to `a[i..j + 1]`.
+                            right: match range.end {
+                                Some(end) if range.inclusive => Some(Box::new(Expression::Binary(BinaryExpression {
+                                    span: end.span().clone(),
+                                    op: BinaryOperation::Add,
+                                    right: Box::new(Expression::Value(ValueExpression::Implicit("1".into(), end.span().clone()))),
+                                    left: end,
+                                }))),
+                                end => end,
+                            },
+                        }),
+                        index => Expression::ArrayAccess(ArrayAccessExpression {
+                            span: expr.span() + &self.prev_token.span,
                             array: Box::new(expr),
-                            left: Some(Box::new(left)),
-                            right,
-                        });
-                    } else {
-                        let end = self.expect(Token::RightSquare)?;
-                        expr = Expression::ArrayAccess(ArrayAccessExpression {
-                            span: expr.span() + &end,
-                            array: Box::new(expr),
-                            index: Box::new(left),
-                        });
-                    }
+                            index: Box::new(index),
+                        }),
+                    };
                 }
                 Token::Dot => {
                     if let Some(ident) = self.eat_identifier() {
@@ -460,38 +629,40 @@ impl ParserContext {
                         });
                     } else {
                         let next = self.peek()?;
-                        return Err(SyntaxError::unexpected_str(&next.token, "int or ident", &next.span));
+                        let span = next.span.clone();
+                        expr = self.recover_expression(SyntaxError::unexpected_str(&next.token, "int or ident", &span), span)?;
                     }
                 }
                 Token::LeftParen => {
                     let mut arguments = Vec::new();
-                    let end_span;
                     loop {
-                        let end = self.eat(Token::RightParen);
-                        if let Some(end) = end {
-                            end_span = end.span;
+                        if self.eat(Token::RightParen).is_some() {
                             break;
                         }
-                        arguments.push(self.parse_expression()?);
-                        if self.eat(Token::Comma).is_none() {
-                            end_span = self.expect(Token::RightParen)?;
+                        arguments.push(self.parse_spread_or_expression()?);
+                        if self.eat(Token::Comma).is_none() && self.recover_list_separator(Token::RightParen, "`,` or `)`")? {
                             break;
                         }
                     }
                     expr = Expression::Call(CallExpression {
-                        span: expr.span() + &end_span,
+                        span: expr.span() + &self.prev_token.span,
                         function: Box::new(expr),
                         arguments,
                     });
                 }
-                Token::DoubleColon => {
-                    let ident = self.expect_ident()?;
-                    expr = Expression::CircuitStaticFunctionAccess(CircuitStaticFunctionAccessExpression {
-                        span: expr.span() + &ident.span,
-                        circuit: Box::new(expr),
-                        name: ident,
-                    });
-                }
+                Token::DoubleColon => match self.expect_ident() {
+                    Ok(ident) => {
+                        expr = Expression::CircuitStaticFunctionAccess(CircuitStaticFunctionAccessExpression {
+                            span: expr.span() + &ident.span,
+                            circuit: Box::new(expr),
+                            name: ident,
+                        });
+                    }
+                    Err(err) => {
+                        let span = self.peek()?.span.clone();
+                        expr = self.recover_expression(err, span)?;
+                    }
+                },
                 _ => unimplemented!(),
             }
         }
@@ -499,10 +670,11 @@ impl ParserContext {
     }
 
     ///
-    /// Returns a [`SpreadOrExpression`] AST node if the next tokens represent an
-    /// spread or expression.
+    /// Returns a [`SpreadOrExpression`] AST node if the next tokens represent a
+    /// spread (`...expr`) or a plain expression.
     ///
-    /// This method should only be called in the context of an array access expression.
+    /// Used for any composite-literal element list that allows spreading another
+    /// collection into it: array literals, tuple literals, and call arguments.
     ///
     pub fn parse_spread_or_expression(&mut self) -> SyntaxResult<SpreadOrExpression> {
         Ok(if self.eat(Token::DotDotDot).is_some() {
@@ -512,6 +684,64 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Parses the dimensions following the `;` in an array-init expression
+    /// (`[value; N]`): a single dimension, a comma-separated list (`[value; 2, 3]`),
+    /// or a parenthesized tuple of dimensions (`[value; (2, 3)]`) for multidimensional
+    /// arrays. All three forms produce the same flat list of axes.
+    ///
+    /// This is now the sole definition of `parse_array_dimensions` on `ParserContext`;
+    /// any single-dimension parser that previously lived outside this file should be
+    /// removed rather than kept alongside this one.
+    ///
+    fn parse_array_dimensions(&mut self) -> SyntaxResult<Vec<PositiveNumber>> {
+        let parenthesized = self.eat(Token::LeftParen).is_some();
+
+        let mut dimensions = vec![self.parse_array_dimension()?];
+        while self.eat(Token::Comma).is_some() {
+            dimensions.push(self.parse_array_dimension()?);
+        }
+
+        if parenthesized {
+            self.expect(Token::RightParen)?;
+        }
+        Ok(dimensions)
+    }
+
+    ///
+    /// Parses a single array dimension: a positive integer literal.
+    ///
+    fn parse_array_dimension(&mut self) -> SyntaxResult<PositiveNumber> {
+        match self.eat_int() {
+            Some((value, span)) => Ok(PositiveNumber { value, span }),
+            None => {
+                let next = self.peek()?;
+                Err(SyntaxError::unexpected_str(&next.token, "array dimension", &next.span))
+            }
+        }
+    }
+
+    ///
+    /// Checks that every element of an `ArrayInline` that is itself an array literal
+    /// has the same length as its siblings, so a nested literal like `[[1, 2], [3, 4]]`
+    /// has a well-defined sub-array type without an explicit annotation. Non-array
+    /// elements and spreads are ignored — they're validated elsewhere. Returns an
+    /// error spanning the first row whose length diverges from the first row seen.
+    ///
+    fn check_ragged_array_init(elements: &[SpreadOrExpression]) -> SyntaxResult<()> {
+        let mut row_len = None;
+        for element in elements {
+            if let SpreadOrExpression::Expression(Expression::ArrayInline(row)) = element {
+                match row_len {
+                    None => row_len = Some(row.elements.len()),
+                    Some(len) if len == row.elements.len() => {}
+                    Some(_) => return Err(SyntaxError::ragged_array_init(&row.span)),
+                }
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent an
     /// circuit initialization expression.
@@ -519,10 +749,8 @@ impl ParserContext {
     pub fn parse_circuit_init(&mut self, identifier: Identifier) -> SyntaxResult<Expression> {
         self.expect(Token::LeftCurly)?;
         let mut members = Vec::new();
-        let end_span;
         loop {
-            if let Some(end) = self.eat(Token::RightCurly) {
-                end_span = end.span;
+            if self.eat(Token::RightCurly).is_some() {
                 break;
             }
             let name = self.expect_ident()?;
@@ -539,12 +767,12 @@ impl ParserContext {
                 });
             }
             if self.eat(Token::Comma).is_none() {
-                end_span = self.expect(Token::RightCurly)?;
+                self.expect(Token::RightCurly)?;
                 break;
             }
         }
         Ok(Expression::CircuitInit(CircuitInitExpression {
-            span: &identifier.span + &end_span,
+            span: &identifier.span + &self.prev_token.span,
             name: identifier,
             members,
         }))
@@ -562,7 +790,12 @@ impl ParserContext {
         let SpannedToken { token, span } = self.expect_any()?;
         Ok(match token {
             Token::Int(value) => {
-                let type_ = self.eat_any(INT_TYPES);
+                // A type suffix only counts as part of the literal when it's lexically
+                // glued to it (`5u8`, not `5 u8`) — otherwise `u8` is its own token.
+                let type_ = match self.peek() {
+                    Ok(next) if Self::is_adjacent(&span, &next.span) => self.eat_any(INT_TYPES),
+                    _ => None,
+                };
                 match type_ {
                     Some(SpannedToken {
                         token: Token::Field,
@@ -599,10 +832,13 @@ impl ParserContext {
                     return Err(SyntaxError::unexpected_str(&value.token, "address", &value.span));
                 };
 
-                let end = self.expect(Token::RightParen)?;
-                Expression::Value(ValueExpression::Address(value, span + end))
+                self.expect(Token::RightParen)?;
+                Expression::Value(ValueExpression::Address(value, span + self.prev_token.span.clone()))
             }
             Token::LeftParen => {
+                // `eat_group_partial` lives outside this module and owns its own
+                // coordinate-sign adjacency handling; `Self::is_adjacent` above only
+                // covers the int-literal type-suffix case parsed in this file.
                 if let Some((left, right, span)) = self.eat_group_partial() {
                     return Ok(Expression::Value(ValueExpression::Group(Box::new(GroupValue::Tuple(
                         GroupTuple {
@@ -613,40 +849,43 @@ impl ParserContext {
                     )))));
                 }
                 let mut args = Vec::new();
-                let end_span;
                 loop {
-                    let end = self.eat(Token::RightParen);
-                    if let Some(end) = end {
-                        end_span = end.span;
+                    if self.eat(Token::RightParen).is_some() {
                         break;
                     }
-                    let expr = self.parse_expression()?;
-                    args.push(expr);
-                    if self.eat(Token::Comma).is_none() {
-                        end_span = self.expect(Token::RightParen)?;
+                    args.push(self.parse_spread_or_expression()?);
+                    if self.eat(Token::Comma).is_none() && self.recover_list_separator(Token::RightParen, "`,` or `)`")? {
                         break;
                     }
                 }
                 if args.len() == 1 {
-                    args.remove(0)
+                    match args.remove(0) {
+                        SpreadOrExpression::Expression(expr) => expr,
+                        // `(...a)` collapses to a single value, so there's nothing to
+                        // spread into; a tuple needs at least one more element.
+                        SpreadOrExpression::Spread(expr) => {
+                            let span = &span + expr.span();
+                            return Err(SyntaxError::spread_in_tuple_init(&span));
+                        }
+                    }
                 } else {
                     Expression::TupleInit(TupleInitExpression {
-                        span: span + end_span,
+                        span: span + self.prev_token.span.clone(),
                         elements: args,
                     })
                 }
             }
             Token::LeftSquare => {
-                if let Some(end) = self.eat(Token::RightSquare) {
+                if self.eat(Token::RightSquare).is_some() {
                     return Ok(Expression::ArrayInline(ArrayInlineExpression {
                         elements: Vec::new(),
-                        span: span + end.span,
+                        span: span + self.prev_token.span.clone(),
                     }));
                 }
                 let first = self.parse_spread_or_expression()?;
                 if self.eat(Token::Semicolon).is_some() {
                     let dimensions = self.parse_array_dimensions()?;
-                    let end = self.expect(Token::RightSquare)?;
+                    self.expect(Token::RightSquare)?;
                     let first = match first {
                         SpreadOrExpression::Spread(first) => {
                             let span = &span + first.span();
@@ -655,36 +894,34 @@ impl ParserContext {
                         SpreadOrExpression::Expression(x) => x,
                     };
                     Expression::ArrayInit(ArrayInitExpression {
-                        span: span + end,
+                        span: span + self.prev_token.span.clone(),
                         element: Box::new(first),
                         dimensions,
                     })
                 } else {
-                    let end_span;
                     let mut elements = vec![first];
                     loop {
-                        if let Some(token) = self.eat(Token::RightSquare) {
-                            end_span = token.span;
+                        if self.eat(Token::RightSquare).is_some() {
                             break;
                         }
                         if elements.len() == 1 {
                             self.expect(Token::Comma)?;
                         }
                         elements.push(self.parse_spread_or_expression()?);
-                        if self.eat(Token::Comma).is_none() {
-                            end_span = self.expect(Token::RightSquare)?;
+                        if self.eat(Token::Comma).is_none() && self.recover_list_separator(Token::RightSquare, "`,` or `]`")? {
                             break;
                         }
                     }
+                    Self::check_ragged_array_init(&elements)?;
                     Expression::ArrayInline(ArrayInlineExpression {
                         elements,
-                        span: span + end_span,
+                        span: span + self.prev_token.span.clone(),
                     })
                 }
             }
             Token::Ident(name) => {
                 let ident = Identifier { name, span };
-                if !self.fuzzy_struct_state && self.peek()?.token == Token::LeftCurly {
+                if !self.fuzzy_struct_state && self.check(&Token::LeftCurly) && self.peek_is_circuit_init() {
                     self.parse_circuit_init(ident)?
                 } else {
                     Expression::Identifier(ident)
@@ -695,7 +932,7 @@ impl ParserContext {
                     name: token.to_string().into(),
                     span,
                 };
-                if !self.fuzzy_struct_state && self.peek()?.token == Token::LeftCurly {
+                if !self.fuzzy_struct_state && self.check(&Token::LeftCurly) && self.peek_is_circuit_init() {
                     self.parse_circuit_init(ident)?
                 } else {
                     Expression::Identifier(ident)
@@ -708,9 +945,35 @@ impl ParserContext {
                 };
                 Expression::Identifier(ident)
             }
-            token => {
-                return Err(SyntaxError::unexpected_str(&token, "expression", &span));
+            Token::DotDotEq => {
+                let end = self.parse_ternary_expression()?;
+                let end = Box::new(self.normalize_expression(end));
+                Expression::Range(RangeExpression {
+                    span: span + end.span(),
+                    start: None,
+                    end: Some(end),
+                    inclusive: true,
+                })
+            }
+            Token::DotDot => {
+                let end = if self.is_range_terminator() {
+                    None
+                } else {
+                    let end = self.parse_ternary_expression()?;
+                    Some(Box::new(self.normalize_expression(end)))
+                };
+                let span = match &end {
+                    Some(end) => span + end.span(),
+                    None => span + self.prev_token.span.clone(),
+                };
+                Expression::Range(RangeExpression {
+                    span,
+                    start: None,
+                    end,
+                    inclusive: false,
+                })
             }
+            token => self.recover_expression(SyntaxError::unexpected_str(&token, "expression", &span), span)?,
         })
     }
 }
\ No newline at end of file